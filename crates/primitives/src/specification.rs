@@ -1,5 +1,12 @@
 #![allow(non_camel_case_types)]
 
+use crate::U256;
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+
 /// SpecId and their activation block
 /// Information was obtained from: <https://github.com/ethereum/execution-specs>
 #[repr(u8)]
@@ -25,10 +32,21 @@ pub enum SpecId {
     SHANGHAI = 16,
     CANCUN = 17,
     LATEST = 18,
+    // These OP Stack forks still reserve discriminants here for the existing SpecId-keyed APIs
+    // (`try_from_u8`, `FromStr`, ...), but `Spec::enabled`/`Spec::hardfork_enabled` no longer
+    // special-case them: their `Spec` impls (see `BedrockSpec` and friends below) implement
+    // `Hardfork` and go through the same `ForkOrdering` mechanism a third-party fork would, with
+    // no reserved discriminant of its own.
     #[cfg(feature = "optimism")]
     BEDROCK = 128,
     #[cfg(feature = "optimism")]
     REGOLITH = 129,
+    #[cfg(feature = "optimism")]
+    CANYON = 130,
+    #[cfg(feature = "optimism")]
+    ECOTONE = 131,
+    #[cfg(feature = "optimism")]
+    FJORD = 132,
 }
 
 impl SpecId {
@@ -39,61 +57,403 @@ impl SpecId {
 
 pub use SpecId::*;
 
-impl From<&str> for SpecId {
-    fn from(name: &str) -> Self {
-        match name {
-            "Frontier" => SpecId::FRONTIER,
-            "Homestead" => SpecId::HOMESTEAD,
-            "Tangerine" => SpecId::TANGERINE,
-            "Spurious" => SpecId::SPURIOUS_DRAGON,
-            "Byzantium" => SpecId::BYZANTIUM,
-            "Constantinople" => SpecId::CONSTANTINOPLE,
-            "Petersburg" => SpecId::PETERSBURG,
-            "Istanbul" => SpecId::ISTANBUL,
-            "MuirGlacier" => SpecId::MUIR_GLACIER,
-            "Berlin" => SpecId::BERLIN,
-            "London" => SpecId::LONDON,
-            "Merge" => SpecId::MERGE,
-            "Shanghai" => SpecId::SHANGHAI,
-            "Cancun" => SpecId::CANCUN,
+/// Error returned by [`SpecId::from_str`] for an unrecognized hardfork name.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct UnknownSpecError(String);
+
+impl core::fmt::Display for UnknownSpecError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unknown hardfork name: {}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownSpecError {}
+
+impl core::str::FromStr for SpecId {
+    type Err = UnknownSpecError;
+
+    /// Parses a hardfork name, matching case-insensitively and accepting the aliases
+    /// `"Paris"` (for [`SpecId::MERGE`]), `"ArrowGlacier"`, `"GrayGlacier"`,
+    /// `"FrontierThawing"`, and `"DAOFork"`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let spec = match name.to_lowercase().as_str() {
+            "frontier" => SpecId::FRONTIER,
+            "frontierthawing" => SpecId::FRONTIER_THAWING,
+            "homestead" => SpecId::HOMESTEAD,
+            "daofork" => SpecId::DAO_FORK,
+            "tangerine" => SpecId::TANGERINE,
+            "spurious" | "spuriousdragon" => SpecId::SPURIOUS_DRAGON,
+            "byzantium" => SpecId::BYZANTIUM,
+            "constantinople" => SpecId::CONSTANTINOPLE,
+            "petersburg" => SpecId::PETERSBURG,
+            "istanbul" => SpecId::ISTANBUL,
+            "muirglacier" => SpecId::MUIR_GLACIER,
+            "berlin" => SpecId::BERLIN,
+            "london" => SpecId::LONDON,
+            "arrowglacier" => SpecId::ARROW_GLACIER,
+            "grayglacier" => SpecId::GRAY_GLACIER,
+            "merge" | "paris" => SpecId::MERGE,
+            "shanghai" => SpecId::SHANGHAI,
+            "cancun" => SpecId::CANCUN,
+            "latest" => SpecId::LATEST,
             #[cfg(feature = "optimism")]
-            "Bedrock" => SpecId::BEDROCK,
+            "bedrock" => SpecId::BEDROCK,
             #[cfg(feature = "optimism")]
-            "Regolith" => SpecId::REGOLITH,
-            _ => SpecId::LATEST,
-        }
+            "regolith" => SpecId::REGOLITH,
+            #[cfg(feature = "optimism")]
+            "canyon" => SpecId::CANYON,
+            #[cfg(feature = "optimism")]
+            "ecotone" => SpecId::ECOTONE,
+            #[cfg(feature = "optimism")]
+            "fjord" => SpecId::FJORD,
+            _ => return Err(UnknownSpecError(name.to_string())),
+        };
+        Ok(spec)
     }
 }
 
 impl SpecId {
+    /// Parses a hardfork name the same way [`FromStr`](core::str::FromStr) does, but falls
+    /// back to [`SpecId::LATEST`] instead of returning an error.
+    ///
+    /// Prefer `name.parse::<SpecId>()` when an unrecognized name should be treated as a
+    /// configuration error; this method is for callers that genuinely want "use whatever's
+    /// newest" as the default.
+    pub fn from_str_or_latest(name: &str) -> Self {
+        name.parse().unwrap_or(SpecId::LATEST)
+    }
+
     #[inline]
     pub const fn enabled(our: SpecId, other: SpecId) -> bool {
         our as u8 >= other as u8
     }
 }
 
+/// Source-compatible with the old infallible conversion. Prefer `"name".parse::<SpecId>()` or
+/// [`SpecId::from_str_or_latest`] in new code — this silently falls back to
+/// [`SpecId::LATEST`] on an unrecognized name, which is the exact footgun `FromStr` exists to
+/// avoid.
+impl From<&str> for SpecId {
+    fn from(name: &str) -> Self {
+        Self::from_str_or_latest(name)
+    }
+}
+
+/// A condition that activates a single fork.
+///
+/// Pre-Merge forks are activated by block number, the Merge itself is activated by terminal
+/// total difficulty, and post-Merge forks (Shanghai, Cancun, ...) are activated by timestamp.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ForkCondition {
+    /// Activates once the block number is reached or exceeded.
+    Block(u64),
+    /// Activates once the accumulated total difficulty is reached or exceeded.
+    TotalDifficulty(U256),
+    /// Activates once the block timestamp is reached or exceeded.
+    Timestamp(u64),
+}
+
+/// A single entry in a [`ChainSpec`]'s fork schedule.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ForkActivation {
+    pub spec_id: SpecId,
+    pub condition: ForkCondition,
+}
+
+/// A chain's fork schedule, used to resolve the active [`SpecId`] for a given block context.
+///
+/// Forks are kept sorted in ascending `SpecId` order and resolved by scanning for the highest
+/// fork whose condition is satisfied. Start from [`ChainSpec::mainnet`] and use
+/// [`ChainSpec::push`] to override or add activations for custom networks.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChainSpec {
+    forks: Vec<ForkActivation>,
+}
+
+impl ChainSpec {
+    /// Creates an empty fork schedule.
+    pub fn new() -> Self {
+        Self { forks: Vec::new() }
+    }
+
+    /// Registers a fork activation, replacing any existing entry for the same `spec_id` and
+    /// keeping the schedule sorted by `SpecId`.
+    pub fn push(mut self, spec_id: SpecId, condition: ForkCondition) -> Self {
+        self.forks.retain(|fork| fork.spec_id != spec_id);
+        self.forks.push(ForkActivation { spec_id, condition });
+        self.forks.sort_by_key(|fork| fork.spec_id);
+        self
+    }
+
+    /// The canonical Ethereum mainnet fork schedule.
+    pub fn mainnet() -> Self {
+        Self::new()
+            .push(SpecId::FRONTIER, ForkCondition::Block(0))
+            .push(SpecId::FRONTIER_THAWING, ForkCondition::Block(200_000))
+            .push(SpecId::HOMESTEAD, ForkCondition::Block(1_150_000))
+            .push(SpecId::DAO_FORK, ForkCondition::Block(1_920_000))
+            .push(SpecId::TANGERINE, ForkCondition::Block(2_463_000))
+            .push(SpecId::SPURIOUS_DRAGON, ForkCondition::Block(2_675_000))
+            .push(SpecId::BYZANTIUM, ForkCondition::Block(4_370_000))
+            .push(SpecId::PETERSBURG, ForkCondition::Block(7_280_000))
+            .push(SpecId::ISTANBUL, ForkCondition::Block(9_069_000))
+            .push(SpecId::MUIR_GLACIER, ForkCondition::Block(9_200_000))
+            .push(SpecId::BERLIN, ForkCondition::Block(12_244_000))
+            .push(SpecId::LONDON, ForkCondition::Block(12_965_000))
+            .push(SpecId::ARROW_GLACIER, ForkCondition::Block(13_773_000))
+            .push(SpecId::GRAY_GLACIER, ForkCondition::Block(15_050_000))
+            .push(
+                SpecId::MERGE,
+                ForkCondition::TotalDifficulty(U256::from(58_750_000_000_000_000_000_000u128)),
+            )
+            .push(SpecId::SHANGHAI, ForkCondition::Timestamp(1_681_338_455))
+            .push(SpecId::CANCUN, ForkCondition::Timestamp(1_710_338_135))
+    }
+
+    /// Resolves the active [`SpecId`] for the given block context.
+    ///
+    /// Forks are scanned in ascending order and the highest one whose condition is satisfied
+    /// wins. Timestamp conditions are only evaluated once [`SpecId::MERGE`]'s own condition is
+    /// satisfied (whatever condition kind that is — a chain without a real TTD, such as an OP
+    /// Stack chain, may encode its Merge-equivalent as a block condition), so a
+    /// timestamp-activated fork can never be reported active on a chain that hasn't reached its
+    /// Merge yet, no matter how far in the future `timestamp` is.
+    pub fn spec_id_at(&self, block_number: u64, timestamp: u64, total_difficulty: U256) -> SpecId {
+        let condition_met = |condition: ForkCondition| match condition {
+            ForkCondition::Block(block) => block_number >= block,
+            ForkCondition::TotalDifficulty(ttd) => total_difficulty >= ttd,
+            ForkCondition::Timestamp(ts) => timestamp >= ts,
+        };
+
+        let merge_active = self
+            .forks
+            .iter()
+            .find(|fork| fork.spec_id == SpecId::MERGE)
+            .is_some_and(|fork| condition_met(fork.condition));
+
+        let mut active = SpecId::FRONTIER;
+        for fork in &self.forks {
+            let satisfied = match fork.condition {
+                ForkCondition::Timestamp(_) if fork.spec_id != SpecId::MERGE => {
+                    merge_active && condition_met(fork.condition)
+                }
+                condition => condition_met(condition),
+            };
+            if satisfied {
+                active = fork.spec_id;
+            }
+        }
+        active
+    }
+}
+
+/// Decides whether the Merge/Bedrock transition is active for a given header.
+///
+/// Ethereum activates the Merge once the accumulated total difficulty crosses the terminal
+/// total difficulty (TTD). OP Stack chains have no proof-of-work difficulty, so headers past
+/// the Merge carry a header difficulty of zero and the transition must instead be gated by
+/// block number. This type picks the right rule per chain so header validation can call
+/// [`MergeActivation::is_merge_active`] without branching on `#[cfg(feature = "optimism")]` at
+/// every call site.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MergeActivation {
+    /// Terminal total difficulty. Unused when the `optimism` feature is enabled.
+    #[cfg(not(feature = "optimism"))]
+    pub ttd: U256,
+    /// Block number at which Bedrock activates. OP Stack headers carry zero difficulty past
+    /// the Merge, so activation is gated by block height instead of TTD.
+    #[cfg(feature = "optimism")]
+    pub activation_block: u64,
+}
+
+impl MergeActivation {
+    /// Ethereum mainnet's terminal total difficulty.
+    #[cfg(not(feature = "optimism"))]
+    pub fn mainnet() -> Self {
+        Self {
+            ttd: U256::from(58_750_000_000_000_000_000_000u128),
+        }
+    }
+
+    /// Whether `parent_total_difficulty + header_difficulty` has crossed this chain's
+    /// configured TTD.
+    ///
+    /// The terminal PoW block is the one whose own `header_difficulty` pushes the parent's
+    /// accumulated total difficulty across the TTD, so both quantities must be summed rather
+    /// than comparing the parent's total difficulty alone.
+    #[cfg(not(feature = "optimism"))]
+    pub fn active_at_ttd(&self, parent_total_difficulty: U256, header_difficulty: U256) -> bool {
+        parent_total_difficulty + header_difficulty >= self.ttd
+    }
+
+    /// Whether the Merge/Bedrock transition is active for this header.
+    #[cfg(not(feature = "optimism"))]
+    pub fn is_merge_active(
+        &self,
+        _block_number: u64,
+        total_difficulty: U256,
+        header_difficulty: U256,
+    ) -> bool {
+        self.active_at_ttd(total_difficulty, header_difficulty)
+    }
+
+    /// Whether the Merge/Bedrock transition is active for this header.
+    #[cfg(feature = "optimism")]
+    pub fn is_merge_active(
+        &self,
+        block_number: u64,
+        _total_difficulty: U256,
+        _header_difficulty: U256,
+    ) -> bool {
+        block_number >= self.activation_block
+    }
+}
+
+/// A named hardfork that can be ordered relative to the built-in Ethereum [`SpecId`]s.
+///
+/// Chains built on top of Ethereum (OP Stack and other L2s) need to express "this fork sits at
+/// or above Ethereum fork X" without reserving a `SpecId` discriminant or patching
+/// [`Spec::enabled`]. Implementing this trait is enough for a third-party fork sequence to
+/// participate in [`ForkOrdering`] queries alongside the built-in specs.
+pub trait Hardfork: core::fmt::Debug {
+    /// A stable name, used for equality and hashing of `dyn Hardfork`.
+    fn name(&self) -> &str;
+
+    /// Where this fork sits relative to the built-in Ethereum fork sequence.
+    fn ordering(&self) -> ForkOrdering;
+}
+
+impl PartialEq for dyn Hardfork {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name()
+    }
+}
+
+impl Eq for dyn Hardfork {}
+
+impl core::hash::Hash for dyn Hardfork {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.name().hash(state);
+    }
+}
+
+/// Where a custom [`Hardfork`] sits relative to the built-in Ethereum fork sequence: "at or
+/// above Ethereum fork `base`", broken by `layer` among forks that share the same base (e.g.
+/// Bedrock and Regolith both layer on top of the Merge).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ForkOrdering {
+    /// The Ethereum fork this custom fork is layered on top of.
+    pub base: SpecId,
+    /// Position among other custom forks sharing the same `base`; higher is newer.
+    pub layer: u32,
+}
+
+impl ForkOrdering {
+    pub const fn new(base: SpecId, layer: u32) -> Self {
+        Self { base, layer }
+    }
+
+    /// Whether a fork ordered at `query` is enabled under a spec ordered at `self`.
+    ///
+    /// Forks with a higher `base` are always newer, regardless of `layer`; forks sharing a
+    /// `base` are ordered by `layer`.
+    pub fn enabled(self, query: ForkOrdering) -> bool {
+        if self.base != query.base {
+            self.base >= query.base
+        } else {
+            self.layer >= query.layer
+        }
+    }
+}
+
+/// A registry of custom [`Hardfork`]s, keyed by name, so callers can resolve a previously
+/// registered fork's [`ForkOrdering`] by name instead of holding onto the trait object.
+#[derive(Default)]
+pub struct HardforkRegistry {
+    forks: Vec<Box<dyn Hardfork>>,
+}
+
+impl HardforkRegistry {
+    pub fn new() -> Self {
+        Self { forks: Vec::new() }
+    }
+
+    /// Registers a custom hardfork, replacing any existing entry with the same name.
+    pub fn register(&mut self, fork: Box<dyn Hardfork>) {
+        self.forks.retain(|existing| existing.name() != fork.name());
+        self.forks.push(fork);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn Hardfork> {
+        self.forks
+            .iter()
+            .find(|fork| fork.name() == name)
+            .map(|fork| fork.as_ref())
+    }
+
+    /// The `enabled()`-style query this registry exists for: is the custom fork named `name`
+    /// enabled given the `active` fork's [`ForkOrdering`]? Returns `None` if `name` hasn't been
+    /// registered, so callers can distinguish "not active" from "not a known fork".
+    pub fn enabled(&self, active: ForkOrdering, name: &str) -> Option<bool> {
+        self.get(name).map(|fork| active.enabled(fork.ordering()))
+    }
+}
+
+/// The [`ForkOrdering`] of a built-in `SpecId`.
+///
+/// Plain Ethereum forks are their own base at layer `0`, which makes comparing two of them
+/// degrade to the original ordinal `u8` compare. The OP Stack forks layer on top of the
+/// corresponding Ethereum baseline instead of slotting into the ordinal sequence, the same way
+/// a third-party [`Hardfork`] would.
+fn spec_id_ordering(spec_id: SpecId) -> ForkOrdering {
+    #[cfg(feature = "optimism")]
+    {
+        // Canyon is derived from Shanghai, Ecotone and Fjord are derived from Cancun, so they
+        // each layer on top of the corresponding Ethereum fork rather than the Merge.
+        let ordering = match spec_id {
+            SpecId::BEDROCK => Some(ForkOrdering::new(SpecId::MERGE, 0)),
+            SpecId::REGOLITH => Some(ForkOrdering::new(SpecId::MERGE, 1)),
+            SpecId::CANYON => Some(ForkOrdering::new(SpecId::SHANGHAI, 0)),
+            SpecId::ECOTONE => Some(ForkOrdering::new(SpecId::CANCUN, 0)),
+            SpecId::FJORD => Some(ForkOrdering::new(SpecId::CANCUN, 1)),
+            _ => None,
+        };
+        if let Some(ordering) = ordering {
+            return ordering;
+        }
+    }
+    ForkOrdering::new(spec_id, 0)
+}
+
 pub trait Spec: Sized {
     const SPEC_ID: SpecId;
 
+    /// Where this spec sits for `enabled()` queries, expressed as a [`ForkOrdering`].
+    ///
+    /// Defaults to `Self::SPEC_ID`'s own built-in ordering (see [`spec_id_ordering`]). A
+    /// third-party spec that layers on top of an Ethereum baseline without a `SpecId`
+    /// discriminant overrides this instead of needing one.
+    fn ordering() -> ForkOrdering {
+        spec_id_ordering(Self::SPEC_ID)
+    }
+
+    /// Whether the built-in fork `spec_id` is enabled under `Self`.
     #[inline(always)]
     fn enabled(spec_id: SpecId) -> bool {
-        // If the Spec is Bedrock or Regolith, and the input is not Bedrock or Regolith,
-        // then no hardforks should be enabled after the merge.
-        let is_self_optimism =
-            Self::SPEC_ID == SpecId::BEDROCK || Self::SPEC_ID == SpecId::REGOLITH;
-        let input_not_optimism = spec_id != SpecId::BEDROCK && spec_id != SpecId::REGOLITH;
-        let after_merge = spec_id > SpecId::MERGE;
-
-        // Optimism's Bedrock and Regolith hardforks implement changes on top of the Merge
-        // hardfork. This function is modified to preserve the original behavior of the
-        // spec IDs without having to put hardforks past Merge under
-        // `#[cfg(not(feature = "optimism"))]`.
-        #[cfg(feature = "optimism")]
-        if is_self_optimism && input_not_optimism && after_merge {
-            return false;
-        }
+        Self::ordering().enabled(spec_id_ordering(spec_id))
+    }
 
-        Self::SPEC_ID as u8 >= spec_id as u8
+    /// Whether a third-party [`Hardfork`] is enabled under `Self`, without `query` needing a
+    /// `SpecId` discriminant of its own.
+    #[inline(always)]
+    fn hardfork_enabled(query: &dyn Hardfork) -> bool {
+        Self::ordering().enabled(query.ordering())
     }
 }
 
@@ -108,6 +468,30 @@ macro_rules! spec {
     };
 }
 
+/// Implements [`Hardfork`] for a built-in OP Stack [`Spec`] in terms of its own
+/// [`Spec::ordering`], so the built-in OP Stack forks can be passed to
+/// [`Spec::hardfork_enabled`] and [`HardforkRegistry`] the same way a third-party fork is.
+#[cfg(feature = "optimism")]
+macro_rules! optimism_hardfork {
+    ($spec_name:tt, $name:expr) => {
+        impl core::fmt::Debug for $spec_name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str($name)
+            }
+        }
+
+        impl Hardfork for $spec_name {
+            fn name(&self) -> &str {
+                $name
+            }
+
+            fn ordering(&self) -> ForkOrdering {
+                <$spec_name as Spec>::ordering()
+            }
+        }
+    };
+}
+
 spec!(FRONTIER, FrontierSpec);
 // FRONTIER_THAWING no EVM spec change
 spec!(HOMESTEAD, HomesteadSpec);
@@ -133,11 +517,207 @@ spec!(LATEST, LatestSpec);
 spec!(BEDROCK, BedrockSpec);
 #[cfg(feature = "optimism")]
 spec!(REGOLITH, RegolithSpec);
+#[cfg(feature = "optimism")]
+spec!(CANYON, CanyonSpec);
+#[cfg(feature = "optimism")]
+spec!(ECOTONE, EcotoneSpec);
+#[cfg(feature = "optimism")]
+spec!(FJORD, FjordSpec);
+
+#[cfg(feature = "optimism")]
+optimism_hardfork!(BedrockSpec, "bedrock");
+#[cfg(feature = "optimism")]
+optimism_hardfork!(RegolithSpec, "regolith");
+#[cfg(feature = "optimism")]
+optimism_hardfork!(CanyonSpec, "canyon");
+#[cfg(feature = "optimism")]
+optimism_hardfork!(EcotoneSpec, "ecotone");
+#[cfg(feature = "optimism")]
+optimism_hardfork!(FjordSpec, "fjord");
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_spec_id_from_str_is_case_insensitive() {
+        assert_eq!("london".parse::<SpecId>(), Ok(SpecId::LONDON));
+        assert_eq!("London".parse::<SpecId>(), Ok(SpecId::LONDON));
+        assert_eq!("LONDON".parse::<SpecId>(), Ok(SpecId::LONDON));
+    }
+
+    #[test]
+    fn test_spec_id_from_str_covers_aliases() {
+        assert_eq!("Paris".parse::<SpecId>(), Ok(SpecId::MERGE));
+        assert_eq!("ArrowGlacier".parse::<SpecId>(), Ok(SpecId::ARROW_GLACIER));
+        assert_eq!("GrayGlacier".parse::<SpecId>(), Ok(SpecId::GRAY_GLACIER));
+        assert_eq!(
+            "FrontierThawing".parse::<SpecId>(),
+            Ok(SpecId::FRONTIER_THAWING)
+        );
+        assert_eq!("DAOFork".parse::<SpecId>(), Ok(SpecId::DAO_FORK));
+    }
+
+    #[test]
+    fn test_spec_id_from_str_rejects_unknown_names() {
+        assert!("not-a-fork".parse::<SpecId>().is_err());
+    }
+
+    #[test]
+    fn test_spec_id_from_str_or_latest_falls_back() {
+        assert_eq!(SpecId::from_str_or_latest("london"), SpecId::LONDON);
+        assert_eq!(SpecId::from_str_or_latest("not-a-fork"), SpecId::LATEST);
+    }
+
+    #[test]
+    fn test_spec_id_from_str_compat_shim_matches_from_str_or_latest() {
+        assert_eq!(SpecId::from("london"), SpecId::LONDON);
+        assert_eq!(SpecId::from("not-a-fork"), SpecId::LATEST);
+    }
+
+    #[test]
+    fn test_chain_spec_resolves_pre_merge_forks_by_block_number() {
+        let spec = ChainSpec::mainnet();
+        assert_eq!(spec.spec_id_at(0, 0, U256::ZERO), SpecId::FRONTIER);
+        assert_eq!(spec.spec_id_at(12_244_000, 0, U256::ZERO), SpecId::BERLIN);
+        assert_eq!(spec.spec_id_at(15_050_000, 0, U256::ZERO), SpecId::GRAY_GLACIER);
+    }
+
+    #[test]
+    fn test_chain_spec_ignores_timestamp_before_merge() {
+        let spec = ChainSpec::mainnet();
+        // Even with a timestamp far past Shanghai's activation, the Merge hasn't happened yet,
+        // so the resolved spec must not jump ahead to a timestamp-activated fork.
+        assert_eq!(
+            spec.spec_id_at(15_050_000, 1_710_338_135, U256::ZERO),
+            SpecId::GRAY_GLACIER
+        );
+    }
+
+    #[test]
+    fn test_chain_spec_resolves_post_merge_forks_by_timestamp() {
+        let spec = ChainSpec::mainnet();
+        let ttd = U256::from(58_750_000_000_000_000_000_000u128);
+        assert_eq!(spec.spec_id_at(15_537_394, 1_681_338_000, ttd), SpecId::MERGE);
+        assert_eq!(spec.spec_id_at(15_537_394, 1_681_338_455, ttd), SpecId::SHANGHAI);
+        assert_eq!(spec.spec_id_at(15_537_394, 1_710_338_135, ttd), SpecId::CANCUN);
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn test_chain_spec_resolves_block_gated_merge_equivalent() {
+        // OP Stack chains have no real TTD, so their Merge-equivalent is encoded as a block
+        // condition rather than `ForkCondition::TotalDifficulty`.
+        let spec = ChainSpec::new()
+            .push(SpecId::MERGE, ForkCondition::Block(0))
+            .push(SpecId::CANYON, ForkCondition::Timestamp(100));
+        assert_eq!(spec.spec_id_at(1, 50, U256::ZERO), SpecId::MERGE);
+        assert_eq!(spec.spec_id_at(1, 100, U256::ZERO), SpecId::CANYON);
+    }
+
+    #[cfg(not(feature = "optimism"))]
+    #[test]
+    fn test_merge_activation_by_ttd() {
+        let merge = MergeActivation::mainnet();
+        let ttd = U256::from(58_750_000_000_000_000_000_000u128);
+        assert!(!merge.is_merge_active(15_537_393, ttd - U256::from(1), U256::ZERO));
+        assert!(merge.is_merge_active(15_537_394, ttd, U256::ZERO));
+    }
+
+    #[cfg(not(feature = "optimism"))]
+    #[test]
+    fn test_merge_activation_by_ttd_accounts_for_header_difficulty() {
+        let merge = MergeActivation::mainnet();
+        let ttd = U256::from(58_750_000_000_000_000_000_000u128);
+        // The parent's accumulated total difficulty alone is short of the TTD, but the
+        // terminal PoW block's own difficulty pushes it across.
+        let parent_total_difficulty = ttd - U256::from(100);
+        assert!(!merge.is_merge_active(15_537_393, parent_total_difficulty, U256::from(99)));
+        assert!(merge.is_merge_active(15_537_394, parent_total_difficulty, U256::from(100)));
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn test_merge_activation_by_block_number() {
+        let merge = MergeActivation {
+            activation_block: 105_235_063,
+        };
+        assert!(!merge.is_merge_active(105_235_062, U256::ZERO, U256::ZERO));
+        assert!(merge.is_merge_active(105_235_063, U256::ZERO, U256::ZERO));
+    }
+
+    #[test]
+    fn test_fork_ordering_compares_across_and_within_base() {
+        let bedrock = ForkOrdering::new(SpecId::MERGE, 0);
+        let regolith = ForkOrdering::new(SpecId::MERGE, 1);
+        assert!(regolith.enabled(bedrock));
+        assert!(!bedrock.enabled(regolith));
+
+        let canyon = ForkOrdering::new(SpecId::SHANGHAI, 0);
+        assert!(canyon.enabled(bedrock));
+        assert!(!bedrock.enabled(canyon));
+    }
+
+    /// A stand-in for a third-party fork (e.g. something Arbitrum- or Base-specific) that
+    /// layers on top of the Merge without reserving a `SpecId` discriminant.
+    #[derive(Debug)]
+    struct CustomOrbitFork;
+
+    impl Hardfork for CustomOrbitFork {
+        fn name(&self) -> &str {
+            "orbit"
+        }
+
+        fn ordering(&self) -> ForkOrdering {
+            ForkOrdering::new(SpecId::MERGE, 0)
+        }
+    }
+
+    #[test]
+    fn test_hardfork_registry_answers_enabled_queries_for_custom_forks() {
+        let mut registry = HardforkRegistry::new();
+        registry.register(Box::new(CustomOrbitFork));
+
+        assert_eq!(
+            registry.enabled(ForkOrdering::new(SpecId::MERGE, 0), "orbit"),
+            Some(true)
+        );
+        assert_eq!(
+            registry.enabled(ForkOrdering::new(SpecId::LONDON, 0), "orbit"),
+            Some(false)
+        );
+        assert_eq!(
+            registry.enabled(ForkOrdering::new(SpecId::MERGE, 0), "unknown"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_plain_spec_hardfork_enabled_queries_custom_fork_without_spec_id() {
+        // `CustomOrbitFork` never gets a `SpecId` discriminant, yet built-in `Spec` types can
+        // still ask whether it's enabled, through the same `ForkOrdering` mechanism used for
+        // built-in forks.
+        assert!(!FrontierSpec::hardfork_enabled(&CustomOrbitFork));
+        assert!(MergeSpec::hardfork_enabled(&CustomOrbitFork));
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn test_optimism_spec_hardfork_enabled_queries_custom_fork_without_spec_id() {
+        assert!(BedrockSpec::hardfork_enabled(&CustomOrbitFork));
+        assert!(CanyonSpec::hardfork_enabled(&CustomOrbitFork));
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn test_built_in_op_forks_implement_hardfork() {
+        // Proof that the built-in OP Stack specs participate in the same `Hardfork` mechanism
+        // a third party would use, instead of it being a disconnected, unexercised API.
+        assert_eq!(BedrockSpec.name(), "bedrock");
+        assert_eq!(BedrockSpec.ordering(), ForkOrdering::new(SpecId::MERGE, 0));
+        assert!(CanyonSpec.ordering().enabled(RegolithSpec.ordering()));
+    }
+
     #[cfg(feature = "optimism")]
     #[test]
     fn test_bedrock_post_merge_hardforks() {
@@ -159,4 +739,36 @@ mod tests {
         assert!(RegolithSpec::enabled(SpecId::BEDROCK));
         assert!(RegolithSpec::enabled(SpecId::REGOLITH));
     }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn test_canyon_enables_shanghai_and_earlier_op_forks() {
+        assert!(CanyonSpec::enabled(SpecId::SHANGHAI));
+        assert!(!CanyonSpec::enabled(SpecId::CANCUN));
+        assert!(!CanyonSpec::enabled(SpecId::LATEST));
+        assert!(CanyonSpec::enabled(SpecId::REGOLITH));
+        assert!(CanyonSpec::enabled(SpecId::CANYON));
+        assert!(!CanyonSpec::enabled(SpecId::ECOTONE));
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn test_ecotone_enables_cancun_and_earlier_op_forks() {
+        assert!(EcotoneSpec::enabled(SpecId::SHANGHAI));
+        assert!(EcotoneSpec::enabled(SpecId::CANCUN));
+        assert!(!EcotoneSpec::enabled(SpecId::LATEST));
+        assert!(EcotoneSpec::enabled(SpecId::CANYON));
+        assert!(EcotoneSpec::enabled(SpecId::ECOTONE));
+        assert!(!EcotoneSpec::enabled(SpecId::FJORD));
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn test_fjord_enables_ecotone_and_earlier_op_forks() {
+        assert!(FjordSpec::enabled(SpecId::CANCUN));
+        assert!(FjordSpec::enabled(SpecId::CANYON));
+        assert!(FjordSpec::enabled(SpecId::ECOTONE));
+        assert!(FjordSpec::enabled(SpecId::FJORD));
+        assert!(!FjordSpec::enabled(SpecId::LATEST));
+    }
 }